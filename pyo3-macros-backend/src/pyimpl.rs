@@ -10,6 +10,7 @@ use crate::{
 use proc_macro2::TokenStream;
 use pymethod::GeneratedPyMethod;
 use quote::{format_ident, quote};
+use syn::ext::IdentExt;
 use syn::ImplItemFn;
 use syn::{
     parse::{Parse, ParseStream},
@@ -104,6 +105,8 @@ fn check_pyfunction(pyo3_path: &PyO3CratePath, meth: &mut ImplItemFn) -> syn::Re
     error.map_or(Ok(()), Err)
 }
 
+const COMPARISON_METHODS: [&str; 6] = ["__lt__", "__le__", "__eq__", "__ne__", "__gt__", "__ge__"];
+
 pub fn impl_methods(
     ty: &syn::Type,
     impls: &mut [syn::ImplItem],
@@ -116,6 +119,8 @@ pub fn impl_methods(
     let mut associated_methods = Vec::new();
 
     let mut implemented_proto_fragments = HashSet::new();
+    let mut richcmp = None;
+    let mut comparisons = Vec::new();
 
     for iimpl in impls {
         match iimpl {
@@ -124,6 +129,21 @@ pub fn impl_methods(
                 let mut fun_options = PyFunctionOptions::from_attrs(&mut meth.attrs)?;
                 fun_options.krate = fun_options.krate.or_else(|| options.krate.clone());
 
+                let python_name = fun_options
+                    .name
+                    .as_ref()
+                    .map_or_else(|| meth.sig.ident.unraw(), |name| name.value.0.clone());
+                let python_name = python_name.to_string();
+                if python_name == "__richcmp__" || COMPARISON_METHODS.contains(&&*python_name) {
+                    let attrs = get_cfg_attributes(&meth.attrs);
+                    let cfg = quote!(#(#attrs)*).to_string();
+                    if python_name == "__richcmp__" {
+                        richcmp = Some((meth.sig.ident.span(), cfg));
+                    } else {
+                        comparisons.push((python_name, cfg));
+                    }
+                }
+
                 check_pyfunction(&ctx.pyo3_path, meth)?;
 
                 match pymethod::gen_py_method(ty, &mut meth.sig, &mut meth.attrs, fun_options, ctx)?
@@ -185,6 +205,21 @@ pub fn impl_methods(
     }
     let ctx = &Ctx::new(&options.krate, None);
 
+    if let Some((span, richcmp_cfg)) = richcmp {
+        // Methods under differing `#[cfg]`s may never be compiled together; if they are, the
+        // generated slot implementations conflict instead.
+        if let Some((comparison, _)) = comparisons
+            .iter()
+            .find(|(_, comparison_cfg)| *comparison_cfg == richcmp_cfg)
+        {
+            bail_spanned!(span => format!(
+                "`__richcmp__` cannot be implemented together with `{}`; \
+                 either implement `__richcmp__` or the individual comparison methods",
+                comparison
+            ));
+        }
+    }
+
     add_shared_proto_slots(ty, &mut proto_impls, implemented_proto_fragments, ctx);
 
     let items = match methods_type {
@@ -40,6 +40,34 @@
 //!     })
 //! }
 //! ```
+//!
+//! # Naive and timezone-aware datetimes
+//!
+//! PyO3 never guesses a timezone. A naive `datetime.datetime` (one with `tzinfo=None`) can only
+//! be extracted as `NaiveDateTime`, and an aware one only as `DateTime<Tz>`; anything else is a
+//! `TypeError`. To assume a timezone for naive values, extract a `NaiveDateTime` and attach the
+//! timezone explicitly:
+//!
+//! ```rust
+//! use chrono::{DateTime, NaiveDateTime, Utc};
+//! use pyo3::prelude::*;
+//!
+//! fn extract_assuming_utc(obj: &Bound<'_, PyAny>) -> PyResult<DateTime<Utc>> {
+//!     match obj.extract::<DateTime<Utc>>() {
+//!         Ok(datetime) => Ok(datetime),
+//!         Err(_) => Ok(obj.extract::<NaiveDateTime>()?.and_utc()),
+//!     }
+//! }
+//! #
+//! # fn main() -> PyResult<()> {
+//! #     pyo3::prepare_freethreaded_python();
+//! #     Python::with_gil(|py| {
+//! #         let naive = py.eval(pyo3::ffi::c_str!("__import__('datetime').datetime(2022, 1, 1)"), None, None)?;
+//! #         assert_eq!(extract_assuming_utc(&naive)?.timestamp(), 1640995200);
+//! #         Ok(())
+//! #     })
+//! # }
+//! ```
 
 use crate::conversion::IntoPyObject;
 use crate::exceptions::{PyTypeError, PyUserWarning, PyValueError};
@@ -16,7 +16,7 @@ use std::ffi::{CStr, CString};
 mod err_state;
 mod impls;
 
-use crate::conversion::IntoPyObject;
+use crate::conversion::{FromPyObject, IntoPyObject};
 use err_state::{PyErrState, PyErrStateLazyFnOutput, PyErrStateNormalized};
 use std::convert::Infallible;
 
@@ -93,6 +93,61 @@ impl<'py> DowncastIntoError<'py> {
     }
 }
 
+/// Error returned by [`PyModule::try_import`][crate::types::PyModule::try_import].
+///
+/// Holds the Python exception raised by the import together with the name of the module which
+/// was requested and the contents of `sys.path` at the time of the failure.
+///
+/// If this error is allowed to bubble up into Python code it will raise the original exception.
+#[derive(Debug)]
+pub struct ModuleImportError {
+    name: String,
+    sys_path: Vec<String>,
+    err: PyErr,
+}
+
+impl ModuleImportError {
+    pub(crate) fn new(py: Python<'_>, name: &str, err: PyErr) -> Self {
+        // Collecting `sys.path` is best-effort; failing to read it should not mask the original error.
+        let sys_path = py
+            .import("sys")
+            .and_then(|sys| sys.getattr("path"))
+            .and_then(|path| {
+                path.try_iter()?
+                    .map(|entry| entry?.str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            name: name.to_owned(),
+            sys_path,
+            err,
+        }
+    }
+
+    /// The name of the module which failed to import.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// A snapshot of `sys.path` taken when the import failed.
+    ///
+    /// This is empty if `sys.path` could not be read.
+    pub fn sys_path(&self) -> &[String] {
+        &self.sys_path
+    }
+
+    /// The Python exception raised by the import.
+    pub fn error(&self) -> &PyErr {
+        &self.err
+    }
+
+    /// Consumes this error, returning the Python exception raised by the import.
+    pub fn into_inner(self) -> PyErr {
+        self.err
+    }
+}
+
 /// Helper conversion trait that allows to use custom arguments for lazy exception construction.
 pub trait PyErrArguments: Send + Sync {
     /// Arguments for exception
@@ -753,6 +808,107 @@ impl PyErr {
         }
     }
 
+    /// Adds a synthetic frame to the traceback of this exception.
+    ///
+    /// The frame is reported as originating from `function` in `file` at line `line`, which lets
+    /// errors passing through Rust code show where they went in the Python traceback, e.g.
+    /// `File "src/lib.rs", line 42, in my_crate::parse`.
+    ///
+    /// Each call adds a frame enclosing those added previously, as when the error propagates
+    /// outward, so add the innermost frame first.
+    ///
+    /// If the frame cannot be created (for example because `function` or `file` contain a nul
+    /// byte), the error is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::exceptions::PyValueError;
+    ///
+    /// fn parse(input: &str) -> PyResult<u32> {
+    ///     input.parse().map_err(|_| {
+    ///         Python::with_gil(|py| {
+    ///             PyValueError::new_err("not a number")
+    ///                 .add_traceback_frame(py, "parse", file!(), line!())
+    ///         })
+    ///     })
+    /// }
+    /// # assert!(parse("x").is_err());
+    /// ```
+    #[cfg(not(any(Py_LIMITED_API, PyPy, GraalPy)))]
+    pub fn add_traceback_frame(
+        self,
+        py: Python<'_>,
+        function: &str,
+        file: &str,
+        line: u32,
+    ) -> PyErr {
+        let (function, file, line) = match (
+            CString::new(function),
+            CString::new(file),
+            std::os::raw::c_int::try_from(line),
+        ) {
+            (Ok(function), Ok(file), Ok(line)) => (function, file, line),
+            _ => return self,
+        };
+        // Set aside an exception which is already being raised, so that it is neither lost nor
+        // mistaken for a failure below. This deliberately bypasses `PyErr::take`, which would
+        // resume a pending `PanicException`.
+        let pending = PyErrStateNormalized::take(py);
+        let result = self.traceback_with_frame(py, &function, &file, line);
+        if let Some(pending) = pending {
+            PyErrState::normalized(pending).restore(py);
+        }
+        match result {
+            Ok(traceback) => {
+                let value = self.into_value(py).into_bound(py);
+                unsafe { ffi::PyException_SetTraceback(value.as_ptr(), traceback.as_ptr()) };
+                // Re-read the traceback from the value, which older Python versions store separately.
+                PyErr::from_value(value.into_any())
+            }
+            // Failing to create the frame should not mask the original exception.
+            Err(_) => self,
+        }
+    }
+
+    /// Creates a traceback entry for a synthetic frame enclosing the traceback of this exception.
+    #[cfg(not(any(Py_LIMITED_API, PyPy, GraalPy)))]
+    fn traceback_with_frame<'py>(
+        &self,
+        py: Python<'py>,
+        function: &CStr,
+        file: &CStr,
+        line: std::os::raw::c_int,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        use crate::ffi_ptr_ext::FfiPtrExt;
+
+        let globals = crate::types::PyDict::new(py);
+        let code = unsafe {
+            ffi::PyCode_NewEmpty(file.as_ptr(), function.as_ptr(), line)
+                .cast::<ffi::PyObject>()
+                .assume_owned_or_err(py)?
+        };
+        let frame = unsafe {
+            ffi::PyFrame_New(
+                ffi::PyThreadState_Get(),
+                code.as_ptr().cast(),
+                globals.as_ptr(),
+                std::ptr::null_mut(),
+            )
+            .cast::<ffi::PyObject>()
+            .assume_owned_or_err(py)?
+        };
+        // From Python 3.11 the line number is derived from the code object instead.
+        #[cfg(not(Py_3_11))]
+        unsafe {
+            (*frame.as_ptr().cast::<ffi::PyFrameObject>()).f_lineno = line;
+        }
+        py.import(crate::intern!(py, "types"))?
+            .getattr(crate::intern!(py, "TracebackType"))?
+            .call1((self.traceback(py), frame, 0, line))
+    }
+
     #[inline]
     fn from_state(state: PyErrState) -> PyErr {
         PyErr { state }
@@ -839,6 +995,16 @@ impl<'py> IntoPyObject<'py> for &PyErr {
     }
 }
 
+/// Extracts an exception instance, e.g. one returned by a Python callback, as a [`PyErr`].
+///
+/// Fails with a `TypeError` if the object is not an instance of `BaseException`.
+impl<'py> FromPyObject<'py> for PyErr {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let value = obj.downcast::<PyBaseException>()?;
+        Ok(PyErr::from_value(value.clone().into_any()))
+    }
+}
+
 struct PyDowncastErrorArguments {
     from: Py<PyType>,
     to: Cow<'static, str>,
@@ -918,6 +1084,28 @@ impl std::fmt::Display for DowncastIntoError<'_> {
     }
 }
 
+impl std::fmt::Display for ModuleImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to import module '{}': {} (sys.path: {:?})",
+            self.name, self.err, self.sys_path
+        )
+    }
+}
+
+impl std::error::Error for ModuleImportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.err)
+    }
+}
+
+impl From<ModuleImportError> for PyErr {
+    fn from(err: ModuleImportError) -> PyErr {
+        err.err
+    }
+}
+
 fn display_downcast_error(
     f: &mut std::fmt::Formatter<'_>,
     from: &Bound<'_, PyAny>,
@@ -1159,6 +1347,57 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg(not(any(Py_LIMITED_API, PyPy, GraalPy)))]
+    fn test_pyerr_add_traceback_frame() {
+        use crate::types::traceback::PyTracebackMethods;
+        Python::with_gil(|py| {
+            let err = exceptions::PyValueError::new_err("banana")
+                .add_traceback_frame(py, "inner_fn", "src/inner.rs", 42)
+                .add_traceback_frame(py, "outer_fn", "src/outer.rs", 7);
+            assert!(err.is_instance_of::<exceptions::PyValueError>(py));
+            assert_eq!(
+                err.traceback(py).unwrap().format().unwrap(),
+                "\
+Traceback (most recent call last):
+  File \"src/outer.rs\", line 7, in outer_fn
+  File \"src/inner.rs\", line 42, in inner_fn
+"
+            );
+
+            let err = exceptions::PyValueError::new_err("banana").add_traceback_frame(
+                py,
+                "nul\0fn",
+                "src/lib.rs",
+                1,
+            );
+            assert!(err.traceback(py).is_none());
+        })
+    }
+
+    #[test]
+    #[cfg(not(any(Py_LIMITED_API, PyPy, GraalPy)))]
+    fn test_pyerr_add_traceback_frame_to_panic_exception() {
+        use crate::panic::PanicException;
+        Python::with_gil(|py| {
+            let err = PanicException::new_err("boom").add_traceback_frame(py, "f", "src/f.rs", 1);
+            assert!(err.is_instance_of::<PanicException>(py));
+            assert!(err.traceback(py).is_some());
+        })
+    }
+
+    #[test]
+    #[cfg(not(any(Py_LIMITED_API, PyPy, GraalPy)))]
+    fn test_pyerr_add_traceback_frame_keeps_pending_error() {
+        use crate::exceptions::PyKeyError;
+        Python::with_gil(|py| {
+            PyKeyError::new_err("pending").restore(py);
+            let err = PyValueError::new_err("banana").add_traceback_frame(py, "f", "src/f.rs", 1);
+            assert!(err.traceback(py).is_some());
+            assert!(PyErr::take(py).unwrap().is_instance_of::<PyKeyError>(py));
+        })
+    }
+
     #[test]
     fn warnings() {
         use crate::types::any::PyAnyMethods;
@@ -1223,4 +1462,20 @@ mod tests {
             warnings.call_method0("resetwarnings").unwrap();
         });
     }
+
+    #[test]
+    fn test_pyerr_extract() {
+        use crate::prelude::*;
+
+        Python::with_gil(|py| {
+            let value = py.eval(ffi::c_str!("ValueError('x')"), None, None).unwrap();
+            let err: PyErr = value.extract().unwrap();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert!(err.into_pyobject(py).unwrap().is(&value));
+
+            let not_an_exception = py.eval(ffi::c_str!("'x'"), None, None).unwrap();
+            let err = not_an_exception.extract::<PyErr>().unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
 }
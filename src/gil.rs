@@ -277,7 +277,10 @@ impl ReferencePool {
         drop(pending_decrefs);
 
         for ptr in decrefs {
-            unsafe { ffi::Py_DECREF(ptr.as_ptr()) };
+            unsafe {
+                crate::internal::debug_refcounts::check_refcount(ptr.as_ptr());
+                ffi::Py_DECREF(ptr.as_ptr())
+            };
         }
     }
 }
@@ -381,6 +384,7 @@ pub unsafe fn register_incref(obj: NonNull<ffi::PyObject>) {
 #[track_caller]
 pub unsafe fn register_decref(obj: NonNull<ffi::PyObject>) {
     if gil_is_acquired() {
+        crate::internal::debug_refcounts::check_refcount(obj.as_ptr());
         ffi::Py_DECREF(obj.as_ptr())
     } else {
         #[cfg(not(pyo3_disable_reference_pool))]
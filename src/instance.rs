@@ -354,7 +354,8 @@ where
     where
         T: PyClass<Frozen = True> + Sync,
     {
-        self.1.get()
+        // Safety: The class itself is frozen and `Sync`
+        unsafe { &*self.get_class_object().get_ptr() }
     }
 
     /// Upcast this `Bound<PyClass>` to its base type by reference.
@@ -463,6 +464,15 @@ where
 
     #[inline]
     pub(crate) fn get_class_object(&self) -> &PyClassObject<T> {
+        // Safety: the GIL is held and `self` is a valid object
+        #[cfg(feature = "debug-refcounts")]
+        unsafe {
+            crate::internal::debug_refcounts::check_type(
+                self.as_ptr(),
+                T::type_object_raw(self.py()),
+                T::NAME,
+            )
+        };
         self.1.get_class_object()
     }
 }
@@ -529,7 +539,10 @@ impl<T> Clone for Bound<'_, T> {
 impl<T> Drop for Bound<'_, T> {
     #[inline]
     fn drop(&mut self) {
-        unsafe { ffi::Py_DECREF(self.as_ptr()) }
+        unsafe {
+            crate::internal::debug_refcounts::check_refcount(self.as_ptr());
+            ffi::Py_DECREF(self.as_ptr())
+        }
     }
 }
 
@@ -1,3 +1,4 @@
 //! Holding place for code which is not intended to be reachable from outside of PyO3.
 
+pub(crate) mod debug_refcounts;
 pub(crate) mod get_slot;
@@ -0,0 +1,70 @@
+//! Consistency checks enabled by the `debug-refcounts` feature.
+//!
+//! Checks whose arguments are cheap compile to nothing unless the feature is enabled; the others
+//! only exist with the feature, so their call sites must be `cfg`-gated too. When a check fails the
+//! process is aborted after printing a diagnostic, because continuing with a corrupted reference
+//! count or borrow flag would only move the crash somewhere harder to debug.
+
+use crate::ffi;
+#[cfg(feature = "debug-refcounts")]
+use std::fmt;
+
+#[cfg(feature = "debug-refcounts")]
+#[cold]
+fn fail(message: fmt::Arguments<'_>) -> ! {
+    eprintln!("pyo3 (debug-refcounts): {}", message);
+    std::process::abort()
+}
+
+/// Checks that `obj` has a positive reference count.
+///
+/// # Safety
+///
+/// `obj` must be a pointer to a Python object and the GIL must be held.
+#[inline]
+#[cfg_attr(not(feature = "debug-refcounts"), allow(unused_variables))]
+pub(crate) unsafe fn check_refcount(obj: *mut ffi::PyObject) {
+    #[cfg(feature = "debug-refcounts")]
+    {
+        let refcnt = ffi::Py_REFCNT(obj);
+        if refcnt <= 0 {
+            fail(format_args!(
+                "object at {:p} has a reference count of {}",
+                obj, refcnt
+            ));
+        }
+    }
+}
+
+/// Checks that `obj` is an instance of the type which a wrapper expects.
+///
+/// # Safety
+///
+/// `obj` must be a pointer to a Python object and the GIL must be held.
+#[cfg(feature = "debug-refcounts")]
+#[inline]
+pub(crate) unsafe fn check_type(
+    obj: *mut ffi::PyObject,
+    expected: *mut ffi::PyTypeObject,
+    expected_name: &str,
+) {
+    check_refcount(obj);
+    if ffi::PyObject_TypeCheck(obj, expected) == 0 {
+        fail(format_args!(
+            "object at {:p} is not an instance of `{}`",
+            obj, expected_name
+        ));
+    }
+}
+
+/// Checks that a borrow flag which is about to be released is in a consistent state.
+#[cfg(feature = "debug-refcounts")]
+#[inline]
+pub(crate) fn check_borrow_release(consistent: bool, kind: &str) {
+    if !consistent {
+        fail(format_args!(
+            "released a {} borrow of a pyclass which was not borrowed that way",
+            kind
+        ));
+    }
+}
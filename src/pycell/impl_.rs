@@ -90,7 +90,13 @@ impl BorrowFlag {
         // impossible to get into a bad state from here so relaxed
         // ordering is fine, the decrement only needs to eventually
         // be visible
-        self.0.fetch_sub(1, Ordering::Relaxed);
+        #[cfg_attr(not(feature = "debug-refcounts"), allow(unused_variables))]
+        let previous = self.0.fetch_sub(1, Ordering::Relaxed);
+        #[cfg(feature = "debug-refcounts")]
+        crate::internal::debug_refcounts::check_borrow_release(
+            previous != BorrowFlag::UNUSED && previous != BorrowFlag::HAS_MUTABLE_BORROW,
+            "shared",
+        );
     }
 }
 
@@ -171,6 +177,11 @@ impl PyClassBorrowChecker for BorrowChecker {
     }
 
     fn release_borrow_mut(&self) {
+        #[cfg(feature = "debug-refcounts")]
+        crate::internal::debug_refcounts::check_borrow_release(
+            self.0 .0.load(Ordering::Relaxed) == BorrowFlag::HAS_MUTABLE_BORROW,
+            "mutable",
+        );
         self.0 .0.store(BorrowFlag::UNUSED, Ordering::Release)
     }
 }
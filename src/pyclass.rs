@@ -3,10 +3,12 @@ use crate::{ffi, impl_::pyclass::PyClassImpl, PyTypeInfo};
 use std::{cmp::Ordering, os::raw::c_int};
 
 mod create_type_object;
+mod export;
 mod gc;
 
 pub(crate) use self::create_type_object::{create_type_object, PyClassTypeObject};
 
+pub use self::export::{export_class, ImportedClass};
 pub use self::gc::{PyTraverseError, PyVisit};
 
 /// Types that can be used as Python classes.
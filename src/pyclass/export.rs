@@ -0,0 +1,238 @@
+//! Sharing `#[pyclass]` types between separately compiled extension modules.
+//!
+//! Each extension module built with PyO3 creates its own type object for every `#[pyclass]` it
+//! contains, so an instance created by one extension cannot be downcast by another extension, even
+//! when both were compiled from the same Rust type. [`export_class`] publishes the type object and
+//! an accessor for the Rust data through a versioned [`PyCapsule`], which other extensions load with
+//! [`ImportedClass::import`].
+//!
+//! Only frozen, `Sync` classes can be shared this way, because the exporting and importing
+//! extensions do not share borrow checking state.
+use crate::impl_::pycell::PyClassObject;
+use crate::pyclass::boolean_struct::True;
+use crate::types::{
+    PyAnyMethods, PyCapsule, PyCapsuleMethods, PyModule, PyModuleMethods, PyStringMethods, PyType,
+};
+use crate::{ffi, Bound, Py, PyAny, PyClass, PyResult, Python};
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+
+/// Version of the [`ClassExport`] layout, to be bumped whenever it changes.
+const CLASS_EXPORT_VERSION: u32 = 1;
+
+/// The contents of the capsule created by [`export_class`].
+#[repr(C)]
+struct ClassExport {
+    version: u32,
+    size: usize,
+    align: usize,
+    type_object: *mut ffi::PyTypeObject,
+    get_data: unsafe extern "C" fn(*mut ffi::PyObject) -> *const c_void,
+}
+
+// Safety: the type object pointer is only dereferenced while holding the GIL.
+unsafe impl Send for ClassExport {}
+
+unsafe extern "C" fn get_data<T: PyClass>(obj: *mut ffi::PyObject) -> *const c_void {
+    (*obj.cast::<PyClassObject<T>>()).get_ptr() as *const c_void
+}
+
+fn export_attribute_name<T: PyClass>() -> String {
+    format!("__pyo3_export_{}__", T::NAME)
+}
+
+/// Exports the class `T` from `module` so that other extension modules can access its instances
+/// using [`ImportedClass`].
+///
+/// This adds a capsule attribute to the module; the module should also be importable under its
+/// `__name__` so that other extensions can find it.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::pyclass::export_class;
+///
+/// #[pyclass(frozen)]
+/// pub struct Token {
+///     pub id: u64,
+/// }
+///
+/// #[pymodule]
+/// fn tokens(m: &Bound<'_, PyModule>) -> PyResult<()> {
+///     m.add_class::<Token>()?;
+///     export_class::<Token>(m)
+/// }
+/// ```
+pub fn export_class<T>(module: &Bound<'_, PyModule>) -> PyResult<()>
+where
+    T: PyClass<Frozen = True> + Sync,
+{
+    let py = module.py();
+    let attribute = export_attribute_name::<T>();
+    let name = CString::new(format!("{}.{}", module.name()?.to_cow()?, attribute))?;
+    let export = ClassExport {
+        version: CLASS_EXPORT_VERSION,
+        size: std::mem::size_of::<T>(),
+        align: std::mem::align_of::<T>(),
+        type_object: T::type_object_raw(py),
+        get_data: get_data::<T>,
+    };
+    // `setattr` rather than `add`, to keep the capsule out of the module's `__all__`
+    module.setattr(attribute.as_str(), PyCapsule::new(py, export, Some(name))?)
+}
+
+/// A `#[pyclass]` exported by another extension module with [`export_class`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pyo3::prelude::*;
+/// use pyo3::pyclass::ImportedClass;
+///
+/// // Usually defined in a crate shared by both extension modules.
+/// #[pyclass(frozen)]
+/// pub struct Token {
+///     pub id: u64,
+/// }
+///
+/// #[pyfunction]
+/// fn token_id(token: &Bound<'_, PyAny>) -> PyResult<Option<u64>> {
+///     // Safety: the `tokens` module exports the `Token` type from the same crate version.
+///     let imported = unsafe { ImportedClass::<Token>::import(token.py(), "tokens")? };
+///     Ok(imported.get(token).map(|token| token.id))
+/// }
+///
+/// #[pymodule]
+/// fn consumer(m: &Bound<'_, PyModule>) -> PyResult<()> {
+///     m.add_function(wrap_pyfunction!(token_id, m)?)
+/// }
+/// ```
+pub struct ImportedClass<T> {
+    // Keeps `export` alive.
+    _capsule: Py<PyCapsule>,
+    export: *const ClassExport,
+    _marker: PhantomData<T>,
+}
+
+// Safety: the exported data is never mutated, and the capsule owning it is only released
+// through `Py`, which is safe to drop on any thread.
+unsafe impl<T: Sync> Send for ImportedClass<T> {}
+unsafe impl<T: Sync> Sync for ImportedClass<T> {}
+
+impl<T> ImportedClass<T>
+where
+    T: PyClass<Frozen = True> + Sync,
+{
+    /// Imports the class `T` exported by the module `module`.
+    ///
+    /// Returns a `TypeError` if the exported class has a different layout than `T`.
+    ///
+    /// # Safety
+    ///
+    /// The class exported by `module` must have been compiled from the same Rust type as `T`,
+    /// e.g. from the same version of a crate shared by both extension modules.
+    pub unsafe fn import(py: Python<'_>, module: &str) -> PyResult<Self> {
+        let capsule = py
+            .import(module)?
+            .getattr(export_attribute_name::<T>())?
+            .downcast_into::<PyCapsule>()?;
+        let expected_name = format!("{}.{}", module, export_attribute_name::<T>());
+        if capsule.name()?.map(|name| name.to_bytes()) != Some(expected_name.as_bytes()) {
+            return Err(crate::exceptions::PyTypeError::new_err(format!(
+                "capsule for class '{}' has an unexpected name",
+                T::NAME
+            )));
+        }
+        let export: &ClassExport = capsule.reference();
+        if export.version != CLASS_EXPORT_VERSION
+            || export.size != std::mem::size_of::<T>()
+            || export.align != std::mem::align_of::<T>()
+        {
+            return Err(crate::exceptions::PyTypeError::new_err(format!(
+                "class '{}' exported by '{}' is not compatible with this extension",
+                T::NAME,
+                module
+            )));
+        }
+        Ok(Self {
+            export,
+            _capsule: capsule.unbind(),
+            _marker: PhantomData,
+        })
+    }
+
+    fn export(&self) -> &ClassExport {
+        // Safety: the capsule owning the export is kept alive by `self`
+        unsafe { &*self.export }
+    }
+
+    /// Returns the type object of the exported class.
+    pub fn type_object<'py>(&self, py: Python<'py>) -> Bound<'py, PyType> {
+        unsafe { PyType::from_borrowed_type_ptr(py, self.export().type_object) }
+    }
+
+    /// Returns whether `obj` is an instance of the exported class or one of its subclasses.
+    pub fn is_instance(&self, obj: &Bound<'_, PyAny>) -> bool {
+        unsafe { ffi::PyObject_TypeCheck(obj.as_ptr(), self.export().type_object) != 0 }
+    }
+
+    /// Returns a reference to the Rust data of `obj` if it is an instance of the exported class.
+    pub fn get<'a>(&'a self, obj: &'a Bound<'_, PyAny>) -> Option<&'a T> {
+        if self.is_instance(obj) {
+            // Safety: `obj` is an instance of the exported class, which has the same layout as
+            // `T`, and the class is frozen so no mutable borrows can exist.
+            Some(unsafe { &*(self.export().get_data)(obj.as_ptr()).cast::<T>() })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use super::*;
+    use crate::types::PyDict;
+
+    #[crate::pyclass(crate = "crate", frozen)]
+    struct Exported {
+        value: u32,
+    }
+
+    #[test]
+    fn test_export_and_import_class() {
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "pyo3_test_export_class").unwrap();
+            export_class::<Exported>(&module).unwrap();
+            assert!(!module
+                .index()
+                .unwrap()
+                .contains(export_attribute_name::<Exported>())
+                .unwrap());
+            py.import("sys")
+                .unwrap()
+                .getattr("modules")
+                .unwrap()
+                .set_item("pyo3_test_export_class", &module)
+                .unwrap();
+
+            let imported =
+                unsafe { ImportedClass::<Exported>::import(py, "pyo3_test_export_class") }.unwrap();
+            assert!(imported.type_object(py).is(&PyType::new::<Exported>(py)));
+
+            let obj = Bound::new(py, Exported { value: 42 }).unwrap().into_any();
+            assert_eq!(imported.get(&obj).unwrap().value, 42);
+            assert!(imported.get(PyDict::new(py).as_any()).is_none());
+        })
+    }
+
+    #[test]
+    fn test_import_class_not_exported() {
+        Python::with_gil(|py| {
+            let result = unsafe { ImportedClass::<Exported>::import(py, "builtins") };
+            assert!(result.is_err());
+        })
+    }
+}
@@ -117,6 +117,36 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
     where
         N: IntoPyObject<'py, Target = PyString>;
 
+    /// Retrieves an attribute value, returning `None` if the attribute does not exist.
+    ///
+    /// This is equivalent to the Python expression `getattr(self, attr_name, None)`, except that
+    /// an attribute whose value is `None` is returned as `Some`. Only `AttributeError` is
+    /// suppressed; any other exception raised while looking up the attribute is returned as an
+    /// error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pyo3::{prelude::*, intern};
+    /// #
+    /// #[pyfunction]
+    /// fn abiflags(sys: &Bound<'_, PyModule>) -> PyResult<String> {
+    ///     // `sys.abiflags` only exists on POSIX
+    ///     match sys.getattr_opt(intern!(sys.py(), "abiflags"))? {
+    ///         Some(flags) => flags.extract(),
+    ///         None => Ok(String::new()),
+    ///     }
+    /// }
+    /// #
+    /// # Python::with_gil(|py| {
+    /// #    let sys = py.import("sys").unwrap();
+    /// #    abiflags(&sys).unwrap();
+    /// # });
+    /// ```
+    fn getattr_opt<N>(&self, attr_name: N) -> PyResult<Option<Bound<'py, PyAny>>>
+    where
+        N: IntoPyObject<'py, Target = PyString>;
+
     /// Sets an attribute value.
     ///
     /// This is equivalent to the Python expression `self.attr_name = value`.
@@ -869,6 +899,13 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
     /// This is equivalent to the Python expression `dir(self)`.
     fn dir(&self) -> PyResult<Bound<'py, PyList>>;
 
+    /// Returns the size of this object in bytes, as reported by the Python runtime.
+    ///
+    /// This is equivalent to the Python expression `sys.getsizeof(self)`. Like `sys.getsizeof`,
+    /// only the memory directly owned by the object is counted, not that of the objects it
+    /// refers to.
+    fn sizeof(&self) -> PyResult<usize>;
+
     /// Checks whether this object is an instance of type `ty`.
     ///
     /// This is equivalent to the Python expression `isinstance(self, ty)`.
@@ -974,6 +1011,46 @@ impl<'py> PyAnyMethods<'py> for Bound<'py, PyAny> {
         )
     }
 
+    fn getattr_opt<N>(&self, attr_name: N) -> PyResult<Option<Bound<'py, PyAny>>>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+    {
+        #[cfg(Py_3_13)]
+        fn inner<'py>(
+            any: &Bound<'py, PyAny>,
+            attr_name: Borrowed<'_, '_, PyString>,
+        ) -> PyResult<Option<Bound<'py, PyAny>>> {
+            let mut result = std::ptr::null_mut();
+            match unsafe {
+                ffi::PyObject_GetOptionalAttr(any.as_ptr(), attr_name.as_ptr(), &mut result)
+            } {
+                0 => Ok(None),
+                1 => Ok(Some(unsafe { result.assume_owned(any.py()) })),
+                _ => Err(PyErr::fetch(any.py())),
+            }
+        }
+
+        #[cfg(not(Py_3_13))]
+        fn inner<'py>(
+            any: &Bound<'py, PyAny>,
+            attr_name: Borrowed<'_, 'py, PyString>,
+        ) -> PyResult<Option<Bound<'py, PyAny>>> {
+            match any.getattr(attr_name) {
+                Ok(value) => Ok(Some(value)),
+                Err(err) if err.is_instance_of::<PyAttributeError>(any.py()) => Ok(None),
+                Err(err) => Err(err),
+            }
+        }
+
+        inner(
+            self,
+            attr_name
+                .into_pyobject(self.py())
+                .map_err(Into::into)?
+                .as_borrowed(),
+        )
+    }
+
     fn setattr<N, V>(&self, attr_name: N, value: V) -> PyResult<()>
     where
         N: IntoPyObject<'py, Target = PyString>,
@@ -1483,6 +1560,14 @@ impl<'py> PyAnyMethods<'py> for Bound<'py, PyAny> {
         }
     }
 
+    fn sizeof(&self) -> PyResult<usize> {
+        let py = self.py();
+        py.import(crate::intern!(py, "sys"))?
+            .getattr(crate::intern!(py, "getsizeof"))?
+            .call1((self,))?
+            .extract()
+    }
+
     #[inline]
     fn is_instance(&self, ty: &Bound<'py, PyAny>) -> PyResult<bool> {
         let result = unsafe { ffi::PyObject_IsInstance(self.as_ptr(), ty.as_ptr()) };
@@ -1675,6 +1760,32 @@ class NonHeapNonDescriptorInt:
         });
     }
 
+    #[test]
+    fn test_call_and_extract_refcnt() {
+        Python::with_gil(|py| {
+            let obj = py.eval(c_str!("object()"), None, None).unwrap();
+            let first_arg = py
+                .eval(c_str!("lambda *args, **kwargs: args[0]"), None, None)
+                .unwrap();
+            let cnt = obj.get_refcnt();
+
+            first_arg.call1((&obj,)).unwrap();
+            let kwargs = [("key", &obj)].into_py_dict(py).unwrap();
+            first_arg.call((&obj,), Some(&kwargs)).unwrap();
+            first_arg.call((obj.clone(),), None).unwrap();
+            drop(kwargs);
+            assert_eq!(obj.get_refcnt(), cnt);
+
+            let list = vec![obj.clone(); 3].into_pyobject(py).unwrap();
+            let items: Vec<Bound<'_, PyAny>> = list.extract().unwrap();
+            drop((list, items));
+            let tuple = (obj.clone(), &obj).into_pyobject(py).unwrap();
+            let pair: (Bound<'_, PyAny>, crate::Py<PyAny>) = tuple.extract().unwrap();
+            drop((tuple, pair));
+            assert_eq!(obj.get_refcnt(), cnt);
+        });
+    }
+
     #[test]
     fn test_call_method0() {
         Python::with_gil(|py| {
@@ -1731,6 +1842,48 @@ class SimpleClass:
         });
     }
 
+    #[test]
+    fn test_sizeof() {
+        Python::with_gil(|py| {
+            let list = py.eval(ffi::c_str!("[1, 2, 3]"), None, None).unwrap();
+            let expected: usize = py
+                .eval(
+                    ffi::c_str!("__import__('sys').getsizeof([1, 2, 3])"),
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(list.sizeof().unwrap(), expected);
+
+            let empty = PyList::empty(py);
+            assert!(empty.sizeof().unwrap() <= list.sizeof().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_getattr_opt() {
+        Python::with_gil(|py| {
+            let module = py.import("sys").unwrap();
+            assert!(module.getattr_opt("version").unwrap().is_some());
+            assert!(module.getattr_opt("no_such_attribute").unwrap().is_none());
+
+            // Only `AttributeError` is suppressed
+            let obj = py
+                .eval(
+                    ffi::c_str!("type('Raises', (), {'__getattr__': lambda self, name: 1 / 0})()"),
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert!(obj
+                .getattr_opt("anything")
+                .unwrap_err()
+                .is_instance_of::<crate::exceptions::PyZeroDivisionError>(py));
+        })
+    }
+
     #[test]
     fn test_hasattr() {
         Python::with_gil(|py| {
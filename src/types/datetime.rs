@@ -261,6 +261,32 @@ impl PyDateAccess for Bound<'_, PyDate> {
 ///
 /// Values of this type are accessed via PyO3's smart pointers, e.g. as
 /// [`Py<PyDateTime>`][crate::Py] or [`Bound<'py, PyDateTime>`][Bound].
+///
+/// Arithmetic and comparisons use the generic operator methods on [`PyAnyMethods`], which
+/// dispatch to `datetime`'s own implementations:
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::{timezone_utc, PyDateTime, PyDelta};
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     let start = PyDateTime::from_timestamp(py, 0.0, Some(&timezone_utc(py)))?;
+///     let day = PyDelta::new(py, 1, 0, 0, false)?;
+///
+///     // `start + day` and `end - start`
+///     let end = start.add(&day)?.downcast_into::<PyDateTime>()?;
+///     assert!(end.sub(&start)?.eq(&day)?);
+///     assert!(start.lt(&end)?);
+///
+///     let timestamp: f64 = end.call_method0("timestamp")?.extract()?;
+///     assert_eq!(timestamp, 86400.0);
+///     Ok(())
+/// })
+/// # }
+/// ```
+///
+/// [`PyAnyMethods`]: crate::types::PyAnyMethods
 #[repr(transparent)]
 pub struct PyDateTime(PyAny);
 pyobject_native_type!(
@@ -212,6 +212,19 @@ pub trait PyDictMethods<'py>: crate::sealed::Sealed {
     /// This method uses [`PyDict_Merge`](https://docs.python.org/3/c-api/dict.html#c.PyDict_Merge) internally,
     /// so should have the same performance as `update`.
     fn update_if_missing(&self, other: &Bound<'_, PyMapping>) -> PyResult<()>;
+
+    /// Retains only the items for which `f` returns `true`, deleting all others.
+    ///
+    /// The keys to delete are collected before any of them are removed, so the dictionary is not
+    /// modified while it is being iterated. If `f` returns an error, the dictionary is left
+    /// unchanged and the error is returned.
+    ///
+    /// # Panics
+    ///
+    /// If `f` adds or removes keys of this dictionary, like [`iter`](PyDictMethods::iter).
+    fn retain<F>(&self, f: F) -> PyResult<()>
+    where
+        F: FnMut(&Bound<'py, PyAny>, &Bound<'py, PyAny>) -> PyResult<bool>;
 }
 
 impl<'py> PyDictMethods<'py> for Bound<'py, PyDict> {
@@ -390,6 +403,22 @@ impl<'py> PyDictMethods<'py> for Bound<'py, PyDict> {
             ffi::PyDict_Merge(self.as_ptr(), other.as_ptr(), 0)
         })
     }
+
+    fn retain<F>(&self, mut f: F) -> PyResult<()>
+    where
+        F: FnMut(&Bound<'py, PyAny>, &Bound<'py, PyAny>) -> PyResult<bool>,
+    {
+        let mut to_delete = Vec::new();
+        for (key, value) in self {
+            if !f(&key, &value)? {
+                to_delete.push(key);
+            }
+        }
+        for key in to_delete {
+            self.del_item(key)?;
+        }
+        Ok(())
+    }
 }
 
 impl<'a, 'py> Borrowed<'a, 'py, PyDict> {
@@ -1463,6 +1492,33 @@ mod tests {
         })
     }
 
+    #[test]
+    fn dict_retain() {
+        use crate::exceptions::PyValueError;
+
+        Python::with_gil(|py| {
+            let dict = [("a", 1), ("b", 2), ("c", 3), ("d", 4)]
+                .into_py_dict(py)
+                .unwrap();
+            dict.retain(|_, value| Ok(value.extract::<i32>()? % 2 == 0))
+                .unwrap();
+            assert_eq!(dict.keys().extract::<Vec<String>>().unwrap(), ["b", "d"]);
+
+            // errors leave the dict unchanged
+            let err = dict
+                .retain(|key, _| {
+                    if key.extract::<String>()? == "d" {
+                        Err(PyValueError::new_err("stop"))
+                    } else {
+                        Ok(false)
+                    }
+                })
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert_eq!(dict.len(), 2);
+        })
+    }
+
     #[test]
     fn dict_update_if_missing() {
         Python::with_gil(|py| {
@@ -22,6 +22,28 @@ use std::os::raw::c_double;
 /// You can usually avoid directly working with this type
 /// by using [`ToPyObject`] and [`extract`][PyAnyMethods::extract]
 /// with [`f32`]/[`f64`].
+///
+/// Rust's formatting of `f64` differs from Python's `repr`: `{}` formats `1.0` as `1` and `1e16`
+/// as `10000000000000000`, while `{:?}` formats `1e16` as `1e16` and `1.5e-7` as `1.5e-7`, where
+/// Python gives `1.0`, `1e+16` and `1.5e-07`. To produce exactly the text Python would, format
+/// through the `float` object:
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::PyFloat;
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     assert_eq!(PyFloat::new(py, 1e16).repr()?, "1e+16");
+///     assert_eq!(PyFloat::new(py, 0.1 + 0.2).str()?, "0.30000000000000004");
+///
+///     // Parsing follows `float(...)`, including underscores and "inf"/"nan".
+///     let value: f64 = py.get_type::<PyFloat>().call1(("1_000.5",))?.extract()?;
+///     assert_eq!(value, 1000.5);
+///     Ok(())
+/// })
+/// # }
+/// ```
 #[repr(transparent)]
 pub struct PyFloat(PyAny);
 
@@ -393,4 +415,24 @@ mod tests {
             assert_eq!(&f_32, py_f32_borrowed);
         });
     }
+
+    #[test]
+    fn test_extract_parsed_float() {
+        Python::with_gil(|py| {
+            let float = py.get_type::<PyFloat>();
+            for (text, expected) in [
+                ("1_000.5", 1000.5),
+                ("1e+16", 1e16),
+                ("-inf", f64::NEG_INFINITY),
+                ("  0.1\n", 0.1),
+            ] {
+                let value: f64 = float.call1((text,)).unwrap().extract().unwrap();
+                assert_eq!(value, expected);
+            }
+            let nan: f64 = float.call1(("nan",)).unwrap().extract().unwrap();
+            assert!(nan.is_nan());
+            let negative_zero: f64 = float.call1(("-0.0",)).unwrap().extract().unwrap();
+            assert!(negative_zero == 0.0 && negative_zero.is_sign_negative());
+        });
+    }
 }
@@ -41,7 +41,7 @@ pub use self::sequence::{PySequence, PySequenceMethods};
 pub use self::set::{PySet, PySetMethods};
 pub use self::slice::{PySlice, PySliceIndices, PySliceMethods};
 #[cfg(not(Py_LIMITED_API))]
-pub use self::string::PyStringData;
+pub use self::string::{PyStringCodePoints, PyStringData};
 #[allow(deprecated)]
 pub use self::string::{PyString, PyStringMethods, PyUnicode};
 pub use self::traceback::{PyTraceback, PyTracebackMethods};
@@ -1,4 +1,4 @@
-use crate::err::{PyErr, PyResult};
+use crate::err::{ModuleImportError, PyErr, PyResult};
 use crate::ffi_ptr_ext::FfiPtrExt;
 use crate::impl_::callback::IntoPyCallbackOutput;
 use crate::py_result_ext::PyResultExt;
@@ -110,6 +110,35 @@ impl PyModule {
         Self::import(py, name.into_py(py))
     }
 
+    /// Imports the Python module with the specified name, recording diagnostics on failure.
+    ///
+    /// This behaves like [`PyModule::import`], but on failure returns a [`ModuleImportError`]
+    /// which records the requested module name and a snapshot of `sys.path` alongside the
+    /// Python exception. When embedding Python, a `ModuleNotFoundError` is almost always caused
+    /// by the module search path not being set up as expected, which this makes easy to report.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::exceptions::PyModuleNotFoundError;
+    ///
+    /// Python::with_gil(|py| {
+    ///     let err = PyModule::try_import(py, "no_such_module").unwrap_err();
+    ///     assert_eq!(err.name(), "no_such_module");
+    ///     assert!(err.error().is_instance_of::<PyModuleNotFoundError>(py));
+    ///     for path in err.sys_path() {
+    ///         println!("searched {}", path);
+    ///     }
+    /// });
+    /// ```
+    pub fn try_import<'py>(
+        py: Python<'py>,
+        name: &str,
+    ) -> Result<Bound<'py, PyModule>, ModuleImportError> {
+        Self::import(py, name).map_err(|err| ModuleImportError::new(py, name, err))
+    }
+
     /// Creates and loads a module named `module_name`,
     /// containing the Python code passed to `code`
     /// and pretending to live at `file_name`.
@@ -600,4 +629,22 @@ mod tests {
                 .ends_with("site.py"));
         })
     }
+
+    #[test]
+    fn module_try_import_error() {
+        use crate::exceptions::PyModuleNotFoundError;
+        Python::with_gil(|py| {
+            assert!(PyModule::try_import(py, "builtins").is_ok());
+
+            let err = PyModule::try_import(py, "pyo3_no_such_module").unwrap_err();
+            assert_eq!(err.name(), "pyo3_no_such_module");
+            assert!(!err.sys_path().is_empty());
+            assert!(err
+                .to_string()
+                .starts_with("failed to import module 'pyo3_no_such_module'"));
+
+            let err: crate::PyErr = err.into();
+            assert!(err.is_instance_of::<PyModuleNotFoundError>(py));
+        })
+    }
 }
@@ -58,6 +58,26 @@ impl<'a> PyStringData<'a> {
         }
     }
 
+    /// Returns an iterator over the code points of the string.
+    ///
+    /// Each item is one element of the underlying storage, so this yields the same number of items
+    /// as `len()` of the Python string, without decoding or allocating. Items are `u32` rather
+    /// than `char` because Python strings may contain lone surrogates.
+    pub fn code_points(self) -> PyStringCodePoints<'a> {
+        PyStringCodePoints {
+            data: self,
+            index: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Ucs1(s) => s.len(),
+            Self::Ucs2(s) => s.len(),
+            Self::Ucs4(s) => s.len(),
+        }
+    }
+
     /// Convert the raw data to a Rust string.
     ///
     /// For UCS-1 / UTF-8, returns a borrow into the original slice. For UCS-2 and UCS-4,
@@ -125,6 +145,37 @@ impl<'a> PyStringData<'a> {
     }
 }
 
+/// Iterator over the code points of a [`PyStringData`], created by [`PyStringData::code_points`].
+#[cfg(not(Py_LIMITED_API))]
+#[derive(Clone, Debug)]
+pub struct PyStringCodePoints<'a> {
+    data: PyStringData<'a>,
+    index: usize,
+}
+
+#[cfg(not(Py_LIMITED_API))]
+impl Iterator for PyStringCodePoints<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let code_point = match self.data {
+            PyStringData::Ucs1(s) => u32::from(*s.get(self.index)?),
+            PyStringData::Ucs2(s) => u32::from(*s.get(self.index)?),
+            PyStringData::Ucs4(s) => *s.get(self.index)?,
+        };
+        self.index += 1;
+        Some(code_point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.data.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+#[cfg(not(Py_LIMITED_API))]
+impl ExactSizeIterator for PyStringCodePoints<'_> {}
+
 /// Represents a Python `string` (a Unicode string object).
 ///
 /// Values of this type are accessed via PyO3's smart pointers, e.g. as
@@ -788,6 +839,31 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+    fn test_string_data_code_points() {
+        Python::with_gil(|py| {
+            for source in ["'café'", "'foo\\ud800'", "'哈哈🐈'"] {
+                let s = py
+                    .eval(&std::ffi::CString::new(source).unwrap(), None, None)
+                    .unwrap();
+                let expected: Vec<u32> = py
+                    .eval(
+                        &std::ffi::CString::new(format!("list(map(ord, {}))", source)).unwrap(),
+                        None,
+                        None,
+                    )
+                    .unwrap()
+                    .extract()
+                    .unwrap();
+                let data = unsafe { s.downcast::<PyString>().unwrap().data().unwrap() };
+                let code_points = data.code_points();
+                assert_eq!(code_points.len(), expected.len());
+                assert_eq!(code_points.collect::<Vec<_>>(), expected);
+            }
+        })
+    }
+
     #[test]
     fn test_intern_string() {
         Python::with_gil(|py| {
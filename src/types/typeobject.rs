@@ -21,7 +21,30 @@ pub struct PyType(PyAny);
 pyobject_native_type_core!(PyType, pyobject_native_static_type_object!(ffi::PyType_Type), #checkfunction=ffi::PyType_Check);
 
 impl PyType {
-    /// Creates a new type object.
+    /// Returns the Python type object for `T`.
+    ///
+    /// This works both for native types and for `#[pyclass]` types, for which the type object is
+    /// created lazily on first access. No new type object is created if one already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::{PyDict, PyType};
+    ///
+    /// #[pyclass]
+    /// struct Point;
+    ///
+    /// Python::with_gil(|py| {
+    ///     // The class object can be handed to Python APIs without importing its module.
+    ///     let point_type = PyType::new::<Point>(py);
+    ///     assert_eq!(point_type.name().unwrap(), "Point");
+    ///     assert!(point_type.is(&PyType::new::<Point>(py)));
+    ///
+    ///     let dict_type = PyType::new::<PyDict>(py);
+    ///     assert!(PyDict::new(py).is_instance(&dict_type).unwrap());
+    /// });
+    /// ```
     #[inline]
     pub fn new<T: PyTypeInfo>(py: Python<'_>) -> Bound<'_, PyType> {
         T::type_object(py)
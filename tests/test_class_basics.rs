@@ -714,3 +714,47 @@ fn test_unsendable_dict_with_weakref() {
         );
     });
 }
+
+#[test]
+fn test_class_sizeof() {
+    #[pyclass]
+    struct Inline {
+        _data: [u8; 256],
+    }
+
+    #[pyclass]
+    struct Buffer {
+        data: Vec<u8>,
+    }
+
+    #[pymethods]
+    impl Buffer {
+        fn __sizeof__(slf: &Bound<'_, Self>) -> PyResult<usize> {
+            // The default `object.__sizeof__` reports `tp_basicsize`, which only includes the
+            // inline Rust payload; add the heap allocation owned by `data`.
+            let base: usize = slf
+                .py()
+                .get_type::<PyAny>()
+                .call_method1("__sizeof__", (slf,))?
+                .extract()?;
+            Ok(base + slf.borrow().data.capacity())
+        }
+    }
+
+    Python::with_gil(|py| {
+        let inline = Py::new(py, Inline { _data: [0; 256] }).unwrap();
+        let size = inline.bind(py).sizeof().unwrap();
+        assert!(size >= 256 + std::mem::size_of::<pyo3::ffi::PyObject>());
+
+        let buffer = Py::new(
+            py,
+            Buffer {
+                data: Vec::with_capacity(4096),
+            },
+        )
+        .unwrap();
+        let size = buffer.bind(py).sizeof().unwrap();
+        assert!(size >= 4096);
+        py_run!(py, buffer, "assert buffer.__sizeof__() >= 4096");
+    });
+}
@@ -285,3 +285,30 @@ fn test_struct_custom_ord_comparable() {
         py_assert!(py, var2 var4, "(var2 != var4) == True");
     })
 }
+
+#[pyclass]
+struct CfgComparisons(i32);
+
+#[pymethods]
+impl CfgComparisons {
+    #[cfg(any())]
+    fn __richcmp__(&self, other: &Self, op: pyo3::pyclass::CompareOp) -> bool {
+        op.matches(self.0.cmp(&other.0))
+    }
+
+    #[cfg(not(any()))]
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[test]
+fn test_richcmp_and_comparison_under_different_cfgs() {
+    Python::with_gil(|py| {
+        let a = Py::new(py, CfgComparisons(1)).unwrap();
+        let b = Py::new(py, CfgComparisons(1)).unwrap();
+        let c = Py::new(py, CfgComparisons(2)).unwrap();
+        py_assert!(py, a b, "a == b");
+        py_assert!(py, a c, "a != c");
+    })
+}
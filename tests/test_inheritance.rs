@@ -4,7 +4,7 @@ use pyo3::prelude::*;
 use pyo3::py_run;
 
 use pyo3::ffi;
-use pyo3::types::IntoPyDict;
+use pyo3::types::{IntoPyDict, PyDict, PyType};
 
 #[path = "../src/tests/common.rs"]
 mod common;
@@ -347,3 +347,63 @@ fn test_subclass_ref_counts() {
         );
     })
 }
+
+#[pyclass(subclass)]
+struct RegistryBase {}
+
+#[pymethods]
+impl RegistryBase {
+    #[classmethod]
+    #[pyo3(signature = (**kwargs))]
+    fn __init_subclass__(
+        cls: &Bound<'_, PyType>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        let tag = match kwargs {
+            Some(kwargs) => kwargs.get_item("tag")?,
+            None => None,
+        };
+        cls.setattr("tag", tag)
+    }
+}
+
+#[pyclass]
+struct NameRecorder {
+    #[pyo3(get)]
+    name: Option<String>,
+}
+
+#[pymethods]
+impl NameRecorder {
+    #[new]
+    fn new() -> Self {
+        Self { name: None }
+    }
+
+    fn __set_name__(&mut self, _owner: &Bound<'_, PyAny>, name: String) {
+        self.name = Some(name);
+    }
+}
+
+#[test]
+fn test_init_subclass_and_set_name() {
+    Python::with_gil(|py| {
+        let base = py.get_type::<RegistryBase>();
+        let recorder = py.get_type::<NameRecorder>();
+        py_run!(
+            py,
+            base recorder,
+            r#"
+            class Plain(base):
+                pass
+
+            # class bodies cannot see py_run's locals, so build this one with type()
+            Tagged = type("Tagged", (base,), {"field": recorder()}, tag="custom")
+
+            assert Plain.tag is None
+            assert Tagged.tag == "custom"
+            assert Tagged.field.name == "field"
+            "#
+        );
+    });
+}
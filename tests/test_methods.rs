@@ -127,6 +127,37 @@ fn class_method_with_args() {
     });
 }
 
+#[pyclass]
+struct GenericContainer {}
+
+#[pymethods]
+impl GenericContainer {
+    #[classmethod]
+    fn __class_getitem__<'py>(
+        cls: &Bound<'py, PyType>,
+        item: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        cls.py()
+            .import("types")?
+            .getattr("GenericAlias")?
+            .call1((cls, item))
+    }
+}
+
+#[test]
+#[cfg(Py_3_9)]
+fn class_getitem() {
+    Python::with_gil(|py| {
+        let d = [("C", py.get_type::<GenericContainer>())]
+            .into_py_dict(py)
+            .unwrap();
+        py_assert!(py, *d, "C[int].__origin__ is C");
+        py_assert!(py, *d, "C[int].__args__ == (int,)");
+        py_assert!(py, *d, "C[int, str].__args__ == (int, str)");
+        py_assert!(py, *d, "repr(C[int]).endswith('GenericContainer[int]')");
+    });
+}
+
 #[pyclass]
 struct StaticMethod {}
 
@@ -1202,3 +1233,41 @@ fn test_issue_2988() {
     ) {
     }
 }
+
+#[pyclass]
+struct BorrowedReturns {
+    dict: Py<PyDict>,
+}
+
+#[pymethods]
+impl BorrowedReturns {
+    #[getter]
+    fn dict(&self) -> &Py<PyDict> {
+        &self.dict
+    }
+
+    fn bound_dict<'a, 'py>(&'a self, py: Python<'py>) -> &'a Bound<'py, PyDict> {
+        self.dict.bind(py)
+    }
+
+    fn first_arg<'a, 'py>(&self, args: &'a Bound<'py, PyTuple>) -> Borrowed<'a, 'py, PyAny> {
+        args.get_borrowed_item(0).unwrap()
+    }
+}
+
+#[test]
+fn method_returning_borrowed_reference() {
+    Python::with_gil(|py| {
+        let obj = Py::new(
+            py,
+            BorrowedReturns {
+                dict: PyDict::new(py).unbind(),
+            },
+        )
+        .unwrap();
+        // The returned objects are the same instance, not copies
+        py_assert!(py, obj, "obj.dict is obj.dict");
+        py_assert!(py, obj, "obj.bound_dict() is obj.dict");
+        py_assert!(py, obj, "obj.first_arg((obj,)) is obj");
+    });
+}
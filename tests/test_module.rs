@@ -262,6 +262,44 @@ fn test_module_dunder_all() {
     });
 }
 
+#[test]
+fn test_module_getattr_and_dir() {
+    #[pyfunction]
+    #[pyo3(name = "__getattr__")]
+    fn module_getattr(name: &str) -> PyResult<usize> {
+        match name {
+            "lazy" => Ok(42),
+            _ => Err(pyo3::exceptions::PyAttributeError::new_err(format!(
+                "module has no attribute '{}'",
+                name
+            ))),
+        }
+    }
+
+    #[pyfunction]
+    #[pyo3(name = "__dir__")]
+    fn module_dir() -> Vec<&'static str> {
+        vec!["eager", "lazy"]
+    }
+
+    #[pymodule]
+    fn module_with_getattr(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        m.add("eager", 1)?;
+        m.add_function(wrap_pyfunction!(module_getattr, m)?)?;
+        m.add_function(wrap_pyfunction!(module_dir, m)?)
+    }
+
+    Python::with_gil(|py| {
+        let module = pyo3::wrap_pymodule!(module_with_getattr)(py);
+
+        // PEP 562: `__getattr__` is only consulted for attributes missing from the module dict
+        py_assert!(py, module, "module.eager == 1");
+        py_assert!(py, module, "module.lazy == 42");
+        py_assert!(py, module, "not hasattr(module, 'missing')");
+        py_assert!(py, module, "dir(module) == ['eager', 'lazy']");
+    });
+}
+
 #[pyfunction]
 fn subfunction() -> String {
     "Subfunction".to_string()
@@ -892,3 +892,59 @@ fn test_contains_opt_out() {
         py_expect_exception!(py, no_contains, "'a' in no_contains", PyTypeError);
     })
 }
+
+#[pyclass]
+struct ContextManager {
+    entered: bool,
+    exited_with: Option<String>,
+}
+
+#[pymethods]
+impl ContextManager {
+    fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.entered = true;
+        slf
+    }
+
+    fn __exit__(
+        &mut self,
+        ty: Option<&Bound<'_, PyType>>,
+        _value: Option<&Bound<'_, PyAny>>,
+        _traceback: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<bool> {
+        let ty = match ty {
+            Some(ty) => ty,
+            None => {
+                self.exited_with = Some("no exception".to_owned());
+                return Ok(false);
+            }
+        };
+        self.exited_with = Some(ty.name()?.to_string());
+        // Suppress `ValueError`, let everything else propagate
+        Ok(ty.is(&ty.py().get_type::<PyValueError>()))
+    }
+}
+
+#[test]
+fn context_manager() {
+    Python::with_gil(|py| {
+        let c = Py::new(
+            py,
+            ContextManager {
+                entered: false,
+                exited_with: None,
+            },
+        )
+        .unwrap();
+
+        py_run!(py, c, "with c as ctx: assert ctx is c");
+        assert!(c.borrow(py).entered);
+        assert_eq!(c.borrow(py).exited_with.as_deref(), Some("no exception"));
+
+        py_run!(py, c, "with c: raise ValueError('suppressed')");
+        assert_eq!(c.borrow(py).exited_with.as_deref(), Some("ValueError"));
+
+        py_expect_exception!(py, c, "with c: raise IndexError('raised')", PyIndexError);
+        assert_eq!(c.borrow(py).exited_with.as_deref(), Some("IndexError"));
+    })
+}
@@ -608,3 +608,42 @@ fn test_pyfunction_raw_ident() {
         py_assert!(py, m, "m.enum()");
     })
 }
+
+#[test]
+fn test_pyfunction_python_token_position() {
+    #[pyfunction]
+    fn py_first(py: Python<'_>, a: i32, b: i32) -> Bound<'_, types::PyInt> {
+        (a - b).into_pyobject(py).unwrap()
+    }
+
+    #[pyfunction]
+    fn py_middle(a: i32, py: Python<'_>, b: i32) -> Bound<'_, types::PyInt> {
+        (a - b).into_pyobject(py).unwrap()
+    }
+
+    #[pyfunction]
+    #[pyo3(signature = (a, b = 1))]
+    fn py_last(a: i32, b: i32, py: Python<'_>) -> Bound<'_, types::PyInt> {
+        (a - b).into_pyobject(py).unwrap()
+    }
+
+    #[pyfunction]
+    fn no_py(a: i32, b: i32) -> i32 {
+        a - b
+    }
+
+    Python::with_gil(|py| {
+        let py_first = wrap_pyfunction!(py_first)(py).unwrap();
+        let py_middle = wrap_pyfunction!(py_middle)(py).unwrap();
+        let py_last = wrap_pyfunction!(py_last)(py).unwrap();
+        let no_py = wrap_pyfunction!(no_py)(py).unwrap();
+
+        // The `Python` token is never a Python-visible argument, wherever it is declared.
+        py_assert!(py, py_first, "py_first(5, 3) == 2");
+        py_assert!(py, py_middle, "py_middle(5, 3) == 2");
+        py_assert!(py, py_middle, "py_middle(a=5, b=3) == 2");
+        py_assert!(py, py_last, "py_last(5) == 4");
+        py_assert!(py, py_last, "py_last(5, b=3) == 2");
+        py_assert!(py, no_py, "no_py(5, 3) == 2");
+    })
+}